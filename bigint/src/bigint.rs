@@ -1,7 +1,36 @@
 use crate::as_bytes::AsBytes;
 use crate::trait_impl;
 use itertools::{EitherOrBoth, Itertools};
-use std::{cmp::max, ops::Add};
+use std::{
+    cmp::{max, min, Ordering},
+    ops::{Add, Mul, Sub},
+};
+
+/// Operands with a maximum byte length above this switch from schoolbook to Karatsuba multiplication.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// An error produced while parsing a big integer from a string.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string contained no digits after an optional sign.
+    Empty,
+    /// The radix was outside the supported range of 2 to 36.
+    InvalidRadix(u32),
+    /// A character was not a valid digit in the requested radix.
+    InvalidDigit(char),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "cannot parse big integer from empty string"),
+            ParseError::InvalidRadix(radix) => write!(f, "invalid radix {radix}, expected 2 to 36"),
+            ParseError::InvalidDigit(c) => write!(f, "invalid digit {c:?} for the given radix"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
 
 /// A big integer type, supporting arbitrarily sized integers.
 ///
@@ -40,10 +69,12 @@ impl BigInt {
     /// assert_eq!(bigint.backing(), &vec![1, 2, 3, 4]);
     /// ```
     pub fn from_backing(backing: Vec<u8>) -> Self {
-        Self {
+        let mut out = Self {
             backing,
             discard_carry: false,
-        }
+        };
+        out.normalize();
+        out
     }
 
     /// Constructs a big integer from a given value, given that it can be safely converted to bytes.
@@ -58,10 +89,12 @@ impl BigInt {
     /// assert_eq!(bigint.backing(), &vec![189, 2]);
     /// ```
     pub fn from_value<T: AsBytes>(value: T) -> Self {
-        Self {
+        let mut out = Self {
             backing: value.as_bytes(),
             discard_carry: T::keep_carry(),
-        }
+        };
+        out.normalize();
+        out
     }
 
     /// Constructs a value from the bytes stored, returning None if byte lengths don't match.
@@ -78,6 +111,93 @@ impl BigInt {
         T::from_bytes(&self.backing)
     }
 
+    /// Constructs a value from the bytes stored, zero- or sign-extending to the target width.
+    ///
+    /// Unlike [`to_value`](Self::to_value), which requires the backing length to match the target
+    /// type exactly, this pads the normalized backing out to the type's byte width (with zeros, or
+    /// `0xFF` for a negative value) before converting. `None` is only returned when the value
+    /// genuinely does not fit: when a byte beyond the target width is not part of the sign
+    /// extension, when the sign bit of a signed target disagrees with the value's sign, or when a
+    /// negative value is read as an unsigned type.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigint::bigint::*;
+    /// let bigint = BigInt::from_value(701u16);
+    /// assert_eq!(bigint.try_to_value::<u64>(), Some(701u64));
+    /// assert_eq!(BigInt::from_value(-4i32).try_to_value::<i64>(), Some(-4i64));
+    /// assert_eq!(BigInt::from_value(u64::MAX).try_to_value::<u8>(), None);
+    /// ```
+    pub fn try_to_value<T: AsBytes>(&self) -> Option<T> {
+        let width = std::mem::size_of::<T>();
+        let fill = if self.is_negative() { 0xFF } else { 0x00 };
+
+        let mut bytes = self.backing.clone();
+        if bytes.len() > width {
+            // any bytes beyond the target width must match the sign fill, else the value overflows
+            if bytes[width..].iter().any(|&b| b != fill) {
+                return None;
+            }
+            bytes.truncate(width);
+        } else {
+            bytes.resize(width, fill);
+        }
+
+        // a signed target's sign bit must agree with the value's sign, else the value has overflowed
+        if T::keep_carry() && bytes.last().is_some_and(|b| (b & 0x80 != 0) != self.is_negative()) {
+            return None;
+        }
+
+        // a negative value never fits an unsigned target
+        if !T::keep_carry() && self.is_negative() {
+            return None;
+        }
+
+        T::from_bytes(&bytes)
+    }
+
+    /// Parses a big integer from a string in the given radix (2 to 36), allowing a leading `-`.
+    ///
+    /// The digits are accumulated with `result = result * radix + digit` using the big integer
+    /// multiplication and addition, so arbitrarily long inputs are supported.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigint::bigint::*;
+    /// assert_eq!(BigInt::from_str_radix("701", 10).unwrap(), BigInt::from_value(701u16));
+    /// assert_eq!(BigInt::from_str_radix("dead", 16).unwrap(), BigInt::from_value(0xdeadu16));
+    /// assert_eq!(BigInt::from_str_radix("-4", 10).unwrap().try_to_value::<i32>(), Some(-4i32));
+    /// assert!(BigInt::from_str_radix("12x", 10).is_err());
+    /// ```
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<BigInt, ParseError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ParseError::InvalidRadix(radix));
+        }
+
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        if digits.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let radix_bi = BigInt::from_value(radix as u8);
+        let mut result = BigInt::from_value(0u8);
+
+        for c in digits.chars() {
+            let digit = c.to_digit(radix).ok_or(ParseError::InvalidDigit(c))?;
+            result = &(&result * &radix_bi) + &BigInt::from_value(digit as u8);
+        }
+
+        if negative {
+            result = result.into_signed_negative();
+        }
+
+        Ok(result)
+    }
+
     /// Gets a reference to the backing data of the big integer.
     ///
     /// # Example
@@ -89,6 +209,154 @@ impl BigInt {
     pub fn backing(&self) -> &Vec<u8> {
         &self.backing
     }
+
+    /// Restores the canonical form of the big integer by removing redundant high bytes.
+    ///
+    /// The backing is stored least-significant byte first, so the most-significant byte is the
+    /// last element. Zero is represented by an empty vector. An unsigned value has no trailing
+    /// zero byte; a signed value has no redundant sign-extension byte (`0x00` or `0xFF`), but keeps
+    /// one when it is needed to preserve the sign.
+    fn normalize(&mut self) {
+        if !self.discard_carry {
+            // unsigned: the most-significant byte is never a redundant zero
+            while self.backing.last() == Some(&0) {
+                self.backing.pop();
+            }
+            return;
+        }
+
+        // signed two's-complement: strip redundant sign-extension bytes, but keep one sign byte
+        // whenever removing it would flip the apparent sign of the new most-significant byte
+        let sign = if self.backing.last().is_some_and(|b| b & 0x80 != 0) {
+            0xFF
+        } else {
+            0x00
+        };
+
+        while self.backing.len() > 1 {
+            let len = self.backing.len();
+            let top = self.backing[len - 1];
+            let next_is_negative = self.backing[len - 2] & 0x80 != 0;
+
+            // only drop the top byte if it duplicates the sign and the next byte keeps that sign
+            if top == sign && next_is_negative == (sign == 0xFF) {
+                self.backing.pop();
+            } else {
+                break;
+            }
+        }
+
+        // a lone zero byte collapses to the canonical empty-vector zero
+        if self.backing == [0x00] {
+            self.backing.clear();
+        }
+    }
+
+    /// Whether the big integer represents a negative value in the signed, two's-complement form.
+    ///
+    /// Only the signed (`discard_carry`) representation can be negative, in which case the top bit
+    /// of the most-significant byte is set.
+    fn is_negative(&self) -> bool {
+        self.discard_carry && self.backing.last().is_some_and(|b| b & 0x80 != 0)
+    }
+
+    /// Returns the magnitude of the big integer as a normalized backing vector.
+    ///
+    /// For non-negative values this is just the backing; for negative values it is the two's
+    /// complement negation taken over the current byte width.
+    fn magnitude(&self) -> Vec<u8> {
+        if !self.is_negative() {
+            // drop the non-significant high zero bytes a signed positive may carry for its sign bit
+            let mut out = self.backing.clone();
+            while out.last() == Some(&0) {
+                out.pop();
+            }
+            return out;
+        }
+
+        let mut out: Vec<u8> = self.backing.iter().map(|b| !b).collect();
+        let mut carry = 1u16;
+        for byte in out.iter_mut() {
+            let sum = *byte as u16 + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        }
+
+        while out.last() == Some(&0) {
+            out.pop();
+        }
+        out
+    }
+
+    /// Divides the backing, read as an unsigned value, by a small divisor, returning the quotient
+    /// backing and the remainder.
+    ///
+    /// The bytes are walked most-significant first, carrying the remainder of each step down into
+    /// the next byte, exactly as long division would.
+    fn divmod_small(&self, divisor: u8) -> (Vec<u8>, u8) {
+        let mut quotient = vec![0u8; self.backing.len()];
+        let mut remainder = 0u16;
+
+        for i in (0..self.backing.len()).rev() {
+            let acc = (remainder << 8) | self.backing[i] as u16;
+            quotient[i] = (acc / divisor as u16) as u8;
+            remainder = acc % divisor as u16;
+        }
+
+        while quotient.last() == Some(&0) {
+            quotient.pop();
+        }
+
+        (quotient, remainder as u8)
+    }
+
+    /// Converts a non-negative magnitude into its signed, two's-complement negative representation.
+    ///
+    /// A spare high byte is added first when the top bit is already set, so the sign bit has room
+    /// and the result cannot be mistaken for a positive value.
+    fn into_signed_negative(self) -> BigInt {
+        let mut backing = self.backing;
+        if backing.last().is_some_and(|b| b & 0x80 != 0) {
+            backing.push(0);
+        }
+
+        for byte in backing.iter_mut() {
+            *byte = !*byte;
+        }
+        let mut carry = 1u16;
+        for byte in backing.iter_mut() {
+            let sum = *byte as u16 + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        }
+
+        let mut out = BigInt {
+            backing,
+            discard_carry: true,
+        };
+        out.normalize();
+        out
+    }
+
+    /// Multiplies the big integer by `256^n` by prepending `n` zero bytes on the least-significant side.
+    fn shift_bytes(&self, n: usize) -> BigInt {
+        let mut backing = Vec::with_capacity(self.backing.len() + n);
+        backing.resize(n, 0);
+        backing.extend_from_slice(&self.backing);
+        BigInt::from_backing(backing)
+    }
+
+    /// Checks whether the big integer is in canonical form, i.e. that normalizing it changes nothing.
+    ///
+    /// Used only in debug builds to guard against operations producing a non-canonical backing.
+    fn test_invariant(&self) -> bool {
+        let mut canonical = BigInt {
+            backing: self.backing.clone(),
+            discard_carry: self.discard_carry,
+        };
+        canonical.normalize();
+        canonical.backing == self.backing
+    }
 }
 
 trait_impl!(BigInt, Add, add);
@@ -145,7 +413,334 @@ impl Add<&BigInt> for &BigInt {
             out.push(carry);
         }
 
-        // then finally construct a bigint from the backing vector
+        // then finally construct a bigint from the backing vector, which normalizes the result
         BigInt::from_backing(out)
     }
 }
+
+trait_impl!(BigInt, Sub, sub);
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Subtracts one big integer from another.
+    ///
+    /// When both operands are unsigned and the result would be negative, the subtraction
+    /// underflows and saturates to zero. When either operand keeps its carry (the signed,
+    /// two's-complement representation) the borrow out of the final byte is simply discarded, so
+    /// the result wraps in two's complement exactly as the hardware subtraction would.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigint::bigint::*;
+    /// assert_eq!((BigInt::from_value(19u8) - BigInt::from_value(16u8)).to_value(), Some(3u8));
+    /// assert_eq!((BigInt::from_value(258u16) - BigInt::from_value(3u8)).to_value(), Some(255u8));
+    /// // unsigned underflow saturates to zero
+    /// assert_eq!((BigInt::from_value(3u8) - BigInt::from_value(16u8)), BigInt::from_value(0u8));
+    /// ```
+    fn sub(self, rhs: &BigInt) -> Self::Output {
+        let max_len = max(self.backing.len(), rhs.backing.len());
+
+        let mut out = Vec::with_capacity(max_len);
+        let mut borrow = 0;
+
+        // iterate through all bytes of both ints, least-significant first
+        for item in self.backing.iter().zip_longest(rhs.backing.iter()) {
+            // get the byte and number of borrows that occur from this position
+            let (byte, overflow) = match item {
+                EitherOrBoth::Both(left, right) => {
+                    // subtract the right byte then the incoming borrow, tracking both underflows
+                    let (output, borrow_a) = left.overflowing_sub(*right);
+                    let (output, borrow_b) = output.overflowing_sub(borrow);
+
+                    // sum the underflows as ints so that two borrows are counted as 2
+                    (output, borrow_a as u8 + borrow_b as u8)
+                }
+                EitherOrBoth::Left(single) => {
+                    // only the left integer has a byte here, so just subtract the borrow
+                    let (output, overflow) = single.overflowing_sub(borrow);
+                    (output, overflow as u8)
+                }
+                EitherOrBoth::Right(single) => {
+                    // only the right integer has a byte here, so subtract it (and the borrow) from zero
+                    let (output, borrow_a) = 0u8.overflowing_sub(*single);
+                    let (output, borrow_b) = output.overflowing_sub(borrow);
+                    (output, borrow_a as u8 + borrow_b as u8)
+                }
+            };
+
+            out.push(byte);
+            borrow = overflow;
+        }
+
+        // a leftover borrow means the true result was negative
+        if borrow != 0 && !(self.discard_carry || rhs.discard_carry) {
+            // unsigned underflow: saturate to zero
+            out.clear();
+        }
+
+        BigInt::from_backing(out)
+    }
+}
+
+/// Schoolbook long multiplication of two byte slices, least-significant byte first.
+///
+/// The output vector has length `a.len() + b.len()`; each 16-bit partial product is added into
+/// `out[i + j]` with the high byte propagated as a carry into the following positions.
+fn mul_schoolbook(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut out = vec![0u8; a.len() + b.len()];
+
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u16;
+
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u16 * y as u16 + out[i + j] as u16 + carry;
+            out[i + j] = product as u8;
+            carry = product >> 8;
+        }
+
+        // propagate the remaining carry into the higher bytes
+        let mut k = i + b.len();
+        while carry != 0 {
+            let sum = out[k] as u16 + carry;
+            out[k] = sum as u8;
+            carry = sum >> 8;
+            k += 1;
+        }
+    }
+
+    out
+}
+
+/// Multiplies two byte slices, using schoolbook multiplication for small operands and recursing
+/// with Karatsuba once the maximum length passes [`KARATSUBA_THRESHOLD`].
+fn mul_inner(a: &[u8], b: &[u8]) -> BigInt {
+    let max_len = max(a.len(), b.len());
+
+    if max_len <= KARATSUBA_THRESHOLD || a.is_empty() || b.is_empty() {
+        return BigInt::from_backing(mul_schoolbook(a, b));
+    }
+
+    // split each operand into low and high halves at m bytes
+    let m = max_len / 2;
+    let (low_a, high_a) = a.split_at(min(m, a.len()));
+    let (low_b, high_b) = b.split_at(min(m, b.len()));
+
+    let z0 = mul_inner(low_a, low_b);
+    let z2 = mul_inner(high_a, high_b);
+
+    // z1 = (low_a + high_a) * (low_b + high_b) - z2 - z0
+    let sum_a = &BigInt::from_backing(low_a.to_vec()) + &BigInt::from_backing(high_a.to_vec());
+    let sum_b = &BigInt::from_backing(low_b.to_vec()) + &BigInt::from_backing(high_b.to_vec());
+    let z1 = &(&mul_inner(sum_a.backing(), sum_b.backing()) - &z2) - &z0;
+
+    // combine as z2 << 2m + z1 << m + z0
+    &(&z2.shift_bytes(2 * m) + &z1.shift_bytes(m)) + &z0
+}
+
+trait_impl!(BigInt, Mul, mul);
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Multiplies two big integers together.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigint::bigint::*;
+    /// assert_eq!((BigInt::from_value(3u8) * BigInt::from_value(16u8)).to_value(), Some(48u8));
+    /// assert_eq!((BigInt::from_value(255u8) * BigInt::from_value(255u8)).to_value(), Some(65025u16));
+    /// assert_eq!((BigInt::from_value(0u8) * BigInt::from_value(123u8)), BigInt::from_value(0u8));
+    /// ```
+    fn mul(self, rhs: &BigInt) -> Self::Output {
+        mul_inner(&self.backing, &rhs.backing)
+    }
+}
+
+impl PartialEq for BigInt {
+    /// Two big integers are equal when they have the same sign and the same magnitude, regardless
+    /// of how wide a two's-complement backing either one happens to use.
+    fn eq(&self, other: &Self) -> bool {
+        debug_assert!(self.test_invariant() && other.test_invariant());
+        self.is_negative() == other.is_negative() && self.magnitude() == other.magnitude()
+    }
+}
+
+impl Eq for BigInt {}
+
+impl Ord for BigInt {
+    /// Orders two big integers by sign, then by magnitude.
+    ///
+    /// Negative values sort below non-negative ones. Two non-negative values compare by magnitude,
+    /// where the longer magnitude is larger and equal lengths compare bytes most-significant
+    /// downward; for two negative values that magnitude order is reversed.
+    fn cmp(&self, other: &Self) -> Ordering {
+        // compare magnitudes: longer normalized backing is larger, then most-significant byte down
+        fn cmp_magnitude(a: &[u8], b: &[u8]) -> Ordering {
+            a.len()
+                .cmp(&b.len())
+                .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+        }
+
+        match (self.is_negative(), other.is_negative()) {
+            (false, false) => cmp_magnitude(&self.magnitude(), &other.magnitude()),
+            (true, true) => cmp_magnitude(&other.magnitude(), &self.magnitude()),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A type that can report the smaller of two values without taking ownership of either.
+pub trait Minimum {
+    /// Returns a reference to the smaller of `self` and `other`.
+    ///
+    /// Named `minimum` rather than `min` to avoid colliding with the by-value [`Ord::min`].
+    fn minimum<'a>(&'a self, other: &'a Self) -> &'a Self;
+}
+
+impl Minimum for BigInt {
+    /// Returns a reference to the smaller of the two big integers, using the signed [`Ord`] ordering.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigint::bigint::*;
+    /// let a = BigInt::from_value(3u8);
+    /// let b = BigInt::from_value(16u8);
+    /// assert_eq!(a.minimum(&b), &BigInt::from_value(3u8));
+    /// ```
+    fn minimum<'a>(&'a self, other: &'a Self) -> &'a Self {
+        if self <= other {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+/// Finds the smallest element of a slice, returning `None` for an empty slice.
+///
+/// The returned reference borrows from the slice, so no values are cloned.
+///
+/// # Example
+/// ```
+/// # use bigint::bigint::*;
+/// let values = [BigInt::from_value(16u8), BigInt::from_value(3u8), BigInt::from_value(9u8)];
+/// assert_eq!(vec_min(&values), Some(&BigInt::from_value(3u8)));
+/// assert_eq!(vec_min::<BigInt>(&[]), None);
+/// ```
+pub fn vec_min<T: Minimum>(v: &[T]) -> Option<&T> {
+    let mut min: Option<&T> = None;
+
+    for n in v {
+        min = Some(match min {
+            Some(e) => e.minimum(n),
+            None => n,
+        });
+    }
+
+    min
+}
+
+impl std::fmt::Display for BigInt {
+    /// Renders the true base-10 value of the big integer, with a leading `-` for negative values.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigint::bigint::*;
+    /// assert_eq!(format!("{}", BigInt::from_value(701u16)), "701");
+    /// assert_eq!(format!("{}", BigInt::from_value(0u8)), "0");
+    /// assert_eq!(format!("{}", BigInt::from_value(-4i32)), "-4");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut magnitude = BigInt::from_backing(self.magnitude());
+
+        if magnitude.backing.is_empty() {
+            return write!(f, "0");
+        }
+
+        // collect the decimal digits least-significant first by repeated division by 10
+        let mut digits = Vec::new();
+        while !magnitude.backing.is_empty() {
+            let (quotient, remainder) = magnitude.divmod_small(10);
+            digits.push(b'0' + remainder);
+            magnitude = BigInt::from_backing(quotient);
+        }
+
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+
+        for digit in digits.iter().rev() {
+            write!(f, "{}", *digit as char)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::LowerHex for BigInt {
+    /// Renders the magnitude in lowercase hexadecimal, most-significant byte first, with a leading
+    /// `-` for negative values.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigint::bigint::*;
+    /// assert_eq!(format!("{:x}", BigInt::from_value(0xdeadu16)), "dead");
+    /// assert_eq!(format!("{:x}", BigInt::from_value(0u8)), "0");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let magnitude = self.magnitude();
+
+        if magnitude.is_empty() {
+            return write!(f, "0");
+        }
+
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+
+        // the most-significant byte has no leading zero, interior bytes are zero-padded to two digits
+        let mut bytes = magnitude.iter().rev();
+        write!(f, "{:x}", bytes.next().unwrap())?;
+        for byte in bytes {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::UpperHex for BigInt {
+    /// Renders the magnitude in uppercase hexadecimal, most-significant byte first, with a leading
+    /// `-` for negative values.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigint::bigint::*;
+    /// assert_eq!(format!("{:X}", BigInt::from_value(0xdeadu16)), "DEAD");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let magnitude = self.magnitude();
+
+        if magnitude.is_empty() {
+            return write!(f, "0");
+        }
+
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+
+        let mut bytes = magnitude.iter().rev();
+        write!(f, "{:X}", bytes.next().unwrap())?;
+        for byte in bytes {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
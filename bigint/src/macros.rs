@@ -5,7 +5,7 @@ macro_rules! trait_impl {
         impl $trait<&$type> for $type {
             type Output = <&'static $type as $trait<&'static $type>>::Output;
 
-            fn add(self, rhs: &BigInt) -> Self::Output {
+            fn $func(self, rhs: &$type) -> Self::Output {
                 $trait::$func(&self, rhs)
             }
         }
@@ -13,7 +13,7 @@ macro_rules! trait_impl {
         impl $trait<$type> for &$type {
             type Output = <&'static $type as $trait<&'static $type>>::Output;
 
-            fn add(self, rhs: BigInt) -> Self::Output {
+            fn $func(self, rhs: $type) -> Self::Output {
                 $trait::$func(self, &rhs)
             }
         }
@@ -21,7 +21,7 @@ macro_rules! trait_impl {
         impl $trait<$type> for $type {
             type Output = <&'static $type as $trait<&'static $type>>::Output;
 
-            fn add(self, rhs: BigInt) -> Self::Output {
+            fn $func(self, rhs: $type) -> Self::Output {
                 $trait::$func(&self, &rhs)
             }
         }